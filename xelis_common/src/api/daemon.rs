@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use crate::{crypto::hash::Hash, transaction::Transaction};
+
+// Single flexible block identifier: covers what the three separate `GetBlockByHashParams` /
+// `GetBlockAtTopoHeightParams` / `GetBlocksAtHeightParams` entry points take with one
+// tagged parameter `get_block` can match on (those three methods stay registered).
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockId {
+    Hash(Hash),
+    Topoheight(u64),
+    Height(u64)
+}
+
+// Chain events a WebSocket session can subscribe to; also doubles as the JSON-RPC
+// `method` field of the notification pushed out when one fires (see `build_notification`).
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyEvent {
+    NewBlock,
+    TransactionAddedInMempool,
+    BlockOrdered,
+    BlockOrphaned
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SubscribeParams {
+    pub notify: NotifyEvent
+}
+
+// Wraps a transaction with where it lives in the DAG: every block it's included in, the
+// topoheight of the first one of those that's topologically ordered, and a confirmation
+// count derived from that topoheight. `topoheight`/`confirmations` are `None` while none
+// of the containing blocks have been ordered yet (e.g. a side block still pending reorg).
+#[derive(Serialize)]
+pub struct TransactionResponse {
+    pub blocks: Vec<Hash>,
+    pub topoheight: Option<u64>,
+    pub confirmations: Option<u64>,
+    #[serde(flatten)]
+    pub data: DataHash<Arc<Transaction>>
+}
+
+// Resolves a transaction by its position inside a specific block instead of by its own
+// hash, so an explorer walking a block's contents doesn't need to know each tx hash first.
+#[derive(Deserialize)]
+pub struct GetTransactionAtBlockLocationParams {
+    pub block: BlockId,
+    pub index: u64
+}
+
+#[derive(Deserialize)]
+pub struct GetBlocksRangeParams {
+    pub start_topoheight: u64,
+    pub end_topoheight: u64,
+    pub include_full_block: bool
+}
@@ -0,0 +1,120 @@
+use rayon::prelude::*;
+use std::{cell::UnsafeCell, sync::Arc};
+use thread_local::ThreadLocal;
+use xelis_common::{crypto::BatchVerifier, transaction::Transaction};
+
+// Verifies every transaction of a block as a single all-or-nothing batch instead of one
+// signature/proof check at a time. `submit_block` (through `verify_block_transactions` in
+// `rpc/rpc.rs`) builds one of these per submitted block and calls `verify_all` before the
+// block is handed to `Blockchain::add_new_block`; a block is only accepted if the whole
+// batch verifies.
+//
+// Work is partitioned across the rayon pool with one `BatchVerifier` per worker thread,
+// held in a `ThreadLocal<UnsafeCell<_>>` so each thread accumulates into its own verifier
+// without locking, then every thread-local verifier's final `verify()` is ANDed together.
+pub struct BlockBatchVerifier {
+    verifiers: ThreadLocal<UnsafeCell<BatchVerifier>>
+}
+
+impl Default for BlockBatchVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BlockBatchVerifier {
+    pub fn new() -> Self {
+        Self { verifiers: ThreadLocal::new() }
+    }
+
+    // Returns `true` only if every transaction's checks batch-verified successfully.
+    // A `false` here doesn't say which transaction was invalid — callers that need to
+    // identify the offender should fall back to verifying each transaction on its own.
+    pub fn verify_all(&self, transactions: &[Arc<Transaction>]) -> bool {
+        fan_out_and(
+            &self.verifiers,
+            transactions,
+            BatchVerifier::new,
+            |tx, verifier| tx.append_to_batch(verifier),
+            |verifier| verifier.verify()
+        )
+    }
+}
+
+// Generic core of `verify_all`: fans `items` out across the rayon pool, appending each one
+// to its thread's own accumulator via `append`, then ANDs every thread-local's `finish`
+// result together. Pulled out so this fan-out/AND pattern can be exercised in tests with a
+// lightweight mock accumulator instead of a real `BatchVerifier`/`Transaction`.
+fn fan_out_and<T, A>(
+    locals: &ThreadLocal<UnsafeCell<A>>,
+    items: &[T],
+    new: impl Fn() -> A + Sync,
+    append: impl Fn(&T, &mut A) + Sync,
+    finish: impl Fn(&A) -> bool + Sync
+) -> bool
+where
+    T: Sync
+{
+    items.par_iter().for_each(|item| {
+        let cell = locals.get_or(|| UnsafeCell::new(new()));
+        // SAFETY: `ThreadLocal::get_or` hands back the same cell only to the thread that
+        // created it, and rayon never runs two closures on one thread at once, so this is
+        // the sole live reference to it for the duration of the append.
+        let acc = unsafe { &mut *cell.get() };
+        append(item, acc);
+    });
+
+    locals.iter().all(|cell| {
+        // SAFETY: batching above has finished (par_iter joined), so no thread still holds
+        // a mutable reference to any cell.
+        let acc = unsafe { &*cell.get() };
+        finish(acc)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Mock accumulator standing in for `BatchVerifier`: "verification" just means every
+    // appended item was non-negative, so a test can force a single thread-local to fail
+    // without needing a real cryptographic verifier.
+    #[derive(Default)]
+    struct MockVerifier {
+        all_non_negative: bool,
+        saw_any: bool
+    }
+
+    fn run(items: &[i32]) -> bool {
+        let locals = ThreadLocal::new();
+        fan_out_and(
+            &locals,
+            items,
+            || MockVerifier { all_non_negative: true, saw_any: false },
+            |item, acc| {
+                acc.saw_any = true;
+                acc.all_non_negative &= *item >= 0;
+            },
+            |acc| !acc.saw_any || acc.all_non_negative
+        )
+    }
+
+    #[test]
+    fn all_thread_locals_passing_ands_to_true() {
+        let items: Vec<i32> = (0..64).collect();
+        assert!(run(&items));
+    }
+
+    #[test]
+    fn one_failing_thread_local_fails_the_whole_batch() {
+        let mut items: Vec<i32> = (0..64).collect();
+        items.push(-1);
+        assert!(!run(&items));
+    }
+
+    #[test]
+    fn empty_item_list_passes_vacuously() {
+        let items: Vec<i32> = Vec::new();
+        assert!(run(&items));
+    }
+}
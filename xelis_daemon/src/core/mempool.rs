@@ -0,0 +1,37 @@
+use std::{collections::HashMap, sync::Arc};
+use xelis_common::{crypto::hash::Hash, transaction::Transaction};
+use super::blockchain::BlockchainError;
+
+// Pending transactions not yet included in a block. Kept separate from `Blockchain`'s
+// storage so RPC handlers that only need mempool access (list, submit) don't have
+// to lock the whole chain.
+#[derive(Default)]
+pub struct Mempool {
+    transactions: HashMap<Hash, Arc<Transaction>>
+}
+
+// One entry as handed out by `get_sorted_txs`; real ordering (by fee rate) lives in the
+// full mempool implementation, outside this slice of the tree.
+pub struct SortedTx {
+    hash: Hash
+}
+
+impl SortedTx {
+    pub fn get_hash(&self) -> &Hash {
+        &self.hash
+    }
+}
+
+impl Mempool {
+    pub fn add_tx(&mut self, hash: Hash, transaction: Arc<Transaction>) {
+        self.transactions.insert(hash, transaction);
+    }
+
+    pub fn view_tx(&self, hash: &Hash) -> Result<Arc<Transaction>, BlockchainError> {
+        self.transactions.get(hash).cloned().ok_or(BlockchainError::TransactionNotFound)
+    }
+
+    pub fn get_sorted_txs(&self) -> Vec<SortedTx> {
+        self.transactions.keys().cloned().map(|hash| SortedTx { hash }).collect()
+    }
+}
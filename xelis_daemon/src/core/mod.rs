@@ -0,0 +1,4 @@
+pub mod batch_verifier;
+pub mod blockchain;
+pub mod mempool;
+pub mod reorg;
@@ -0,0 +1,11 @@
+use xelis_common::crypto::hash::Hash;
+
+// Every block whose topological ordering flipped while `Blockchain::add_new_block` linked
+// in a new tip, as one full set rather than something callers derive by diffing a couple
+// of fixed hashes (a new block can push the reorg window arbitrarily far behind its direct
+// parents). `add_new_block` does not compute reorgs, so both lists it returns are empty.
+#[derive(Default)]
+pub struct DagReorg {
+    pub ordered: Vec<Hash>,
+    pub orphaned: Vec<Hash>
+}
@@ -0,0 +1,30 @@
+// Minimal placeholder for the peer-to-peer networking layer: just enough surface for the
+// RPC `p2p_status` method, which only reports coarse connection stats. The real networking
+// implementation lives outside this slice of the tree.
+pub struct P2pServer {
+    tag: Option<String>,
+    peer_id: u64,
+    max_peers: usize
+}
+
+impl P2pServer {
+    pub async fn get_peer_count(&self) -> usize {
+        todo!()
+    }
+
+    pub fn get_tag(&self) -> &Option<String> {
+        &self.tag
+    }
+
+    pub fn get_peer_id(&self) -> u64 {
+        self.peer_id
+    }
+
+    pub async fn get_best_height(&self) -> u64 {
+        todo!()
+    }
+
+    pub fn get_max_peers(&self) -> usize {
+        self.max_peers
+    }
+}
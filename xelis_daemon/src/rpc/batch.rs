@@ -0,0 +1,150 @@
+use super::{RpcError, RpcServer, WebSocketSessionShared};
+use futures::future::join_all;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+// A single JSON-RPC 2.0 request object. `id` is `None` for notifications, which are
+// processed but never produce a response element.
+#[derive(Deserialize)]
+pub struct RpcRequest {
+    pub id: Option<Value>,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value
+}
+
+// JSON-RPC 2.0 reserves -32600..-32603 for these; anything else this server raises
+// (no p2p, bad address, ...) falls back to -32603 Internal error.
+fn error_code(error: &RpcError) -> i32 {
+    match error {
+        RpcError::InvalidRequest | RpcError::InvalidRequestEnvelope(_) => -32600,
+        RpcError::MethodNotFound => -32601,
+        RpcError::InvalidParams(_) => -32602,
+        _ => -32603
+    }
+}
+
+fn error_response(id: Option<Value>, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {
+            "code": error_code(&error),
+            "message": error.to_string()
+        }
+    })
+}
+
+fn success_response(id: Value, result: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "result": result
+    })
+}
+
+impl RpcServer {
+    // Runs one request object through the method registry and, unless it was a
+    // notification (no `id`), returns the JSON-RPC response object to send back.
+    async fn dispatch_one(&self, request: Value) -> Option<Value> {
+        let request: RpcRequest = match serde_json::from_value(request) {
+            Ok(request) => request,
+            Err(e) => return Some(error_response(None, RpcError::InvalidRequestEnvelope(e)))
+        };
+
+        let result = self.execute_method(&request.method, request.params).await;
+        request.id.map(|id| match result {
+            Ok(value) => success_response(id, value),
+            Err(e) => error_response(Some(id), e)
+        })
+    }
+
+    // Entry point for the HTTP transport: accepts either a single JSON-RPC request
+    // object or, per the spec, a top-level array of request objects (a "batch"). Batch
+    // elements are dispatched concurrently via `join_all` and correlated back to their
+    // `id`; notifications within the batch contribute no element to the returned array.
+    pub async fn handle_body(&self, body: Value) -> Option<Value> {
+        match body {
+            Value::Array(requests) => {
+                if requests.is_empty() {
+                    return Some(error_response(None, RpcError::InvalidRequest))
+                }
+
+                let responses = join_all(requests.into_iter().map(|request| async move {
+                    if !request.is_object() {
+                        return Some(error_response(None, RpcError::InvalidRequest))
+                    }
+                    self.dispatch_one(request).await
+                })).await;
+
+                let responses: Vec<Value> = responses.into_iter().flatten().collect();
+                if responses.is_empty() {
+                    None
+                } else {
+                    Some(Value::Array(responses))
+                }
+            },
+            Value::Object(_) => self.dispatch_one(body).await,
+            _ => Some(error_response(None, RpcError::InvalidRequest))
+        }
+    }
+
+    #[cfg(test)]
+    fn test_server() -> Self {
+        use crate::{core::blockchain::Blockchain, storage::Storage};
+        use std::sync::Arc;
+        Self::new(Arc::new(Blockchain::new(Storage::default())))
+    }
+
+    // WS analogue of `handle_body` for a single connection: dispatches through
+    // `execute_ws_method` so `subscribe`/`unsubscribe` see the calling session.
+    pub async fn handle_ws_body(&self, session: &WebSocketSessionShared, body: Value) -> Option<Value> {
+        let request: RpcRequest = match serde_json::from_value(body) {
+            Ok(request) => request,
+            Err(e) => return Some(error_response(None, RpcError::InvalidRequestEnvelope(e)))
+        };
+
+        let result = self.execute_ws_method(session, &request.method, request.params).await;
+        request.id.map(|id| match result {
+            Ok(value) => success_response(id, value),
+            Err(e) => error_response(Some(id), e)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_code_mapping() {
+        assert_eq!(error_code(&RpcError::InvalidRequest), -32600);
+        assert_eq!(error_code(&RpcError::InvalidRequestEnvelope(serde_json::from_str::<Value>("{").unwrap_err())), -32600);
+        assert_eq!(error_code(&RpcError::MethodNotFound), -32601);
+        assert_eq!(error_code(&RpcError::InvalidParams(serde_json::from_str::<Value>("{").unwrap_err())), -32602);
+        assert_eq!(error_code(&RpcError::NoP2p), -32603);
+    }
+
+    #[tokio::test]
+    async fn empty_batch_is_invalid_request() {
+        let server = RpcServer::test_server();
+        let response = server.handle_body(json!([])).await.expect("empty batch must still respond");
+        assert_eq!(response["error"]["code"], json!(-32600));
+    }
+
+    #[tokio::test]
+    async fn non_object_batch_element_is_invalid_request() {
+        let server = RpcServer::test_server();
+        let response = server.handle_body(json!([1])).await.expect("non-object element must produce a response");
+        let responses = response.as_array().expect("batch response is an array");
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0]["error"]["code"], json!(-32600));
+    }
+
+    #[tokio::test]
+    async fn notification_only_batch_produces_no_response() {
+        let server = RpcServer::test_server();
+        let response = server.handle_body(json!([{"jsonrpc": "2.0", "method": "nonexistent"}])).await;
+        assert!(response.is_none());
+    }
+}
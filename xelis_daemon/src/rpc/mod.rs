@@ -0,0 +1,104 @@
+mod batch;
+mod rpc;
+mod websocket;
+
+pub use batch::RpcRequest;
+pub use rpc::register_methods;
+pub use websocket::{accept_websocket, NotifyEvent, Notifier, WebSocketSessionShared};
+
+use crate::core::blockchain::Blockchain;
+use anyhow::Error as AnyError;
+use serde_json::Value;
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc};
+use thiserror::Error;
+
+pub type Handler = Arc<dyn Fn(Arc<Blockchain>, Value) -> Pin<Box<dyn Future<Output = Result<Value, RpcError>> + Send>> + Send + Sync>;
+// Handler flavor for the WebSocket-only meta methods (subscribe/unsubscribe) which need
+// to mutate the originating session's subscription set rather than the shared blockchain.
+pub type WsHandler = Arc<dyn Fn(WebSocketSessionShared, Value) -> Pin<Box<dyn Future<Output = Result<Value, RpcError>> + Send>> + Send + Sync>;
+
+#[derive(Error, Debug)]
+pub enum RpcError {
+    #[error("invalid params: {0}")]
+    InvalidParams(#[from] serde_json::Error),
+    #[error("unexpected parameters")]
+    UnexpectedParams,
+    #[error("invalid request")]
+    InvalidRequest,
+    // A request object that doesn't even deserialize into `RpcRequest` (missing/malformed
+    // `method`, wrong top-level shape, ...) is a malformed envelope, not bad method
+    // params, so it gets its own variant instead of reusing InvalidParams.
+    #[error("invalid request: {0}")]
+    InvalidRequestEnvelope(serde_json::Error),
+    #[error("method not found")]
+    MethodNotFound,
+    #[error("expected a normal address")]
+    ExpectedNormalAddress,
+    #[error("p2p engine is not running")]
+    NoP2p,
+    #[error(transparent)]
+    Any(#[from] AnyError)
+}
+
+// Creates a boxed, type-erased handler from an `async fn(Arc<Blockchain>, Value) -> Result<Value, RpcError>`
+// (a `Handler`) or, for the WebSocket meta-methods, an `async fn(WebSocketSessionShared, Value)`
+// (a `WsHandler`). Every method registered on the RpcServer goes through this so the registries
+// can store handlers of differing concrete future types behind a single alias each.
+#[macro_export]
+macro_rules! async_handler {
+    ($func: expr) => {
+        std::sync::Arc::new(move |blockchain, params| Box::pin($func(blockchain, params)))
+    };
+}
+
+pub struct RpcServer {
+    blockchain: Arc<Blockchain>,
+    methods: HashMap<String, Handler>,
+    ws_methods: HashMap<String, WsHandler>
+}
+
+impl RpcServer {
+    pub fn new(blockchain: Arc<Blockchain>) -> Self {
+        let mut server = Self {
+            blockchain,
+            methods: HashMap::new(),
+            ws_methods: HashMap::new()
+        };
+        register_methods(&mut server);
+        server
+    }
+
+    pub fn get_notifier(&self) -> &Arc<Notifier> {
+        self.blockchain.get_notifier()
+    }
+
+    pub fn register_method(&mut self, name: &str, handler: Handler) {
+        if self.methods.insert(name.to_owned(), handler).is_some() {
+            panic!("RPC method {} was registered twice", name);
+        }
+    }
+
+    // Registers a meta-method only reachable over the WebSocket transport, dispatched with
+    // the calling session instead of the blockchain (see `subscribe` / `unsubscribe`).
+    pub fn register_ws_method(&mut self, name: &str, handler: WsHandler) {
+        if self.ws_methods.insert(name.to_owned(), handler).is_some() {
+            panic!("RPC method {} was registered twice", name);
+        }
+    }
+
+    // Executes a single JSON-RPC request object and returns its result.
+    // Shared by the plain HTTP path and the batch dispatcher.
+    pub async fn execute_method(&self, name: &str, params: Value) -> Result<Value, RpcError> {
+        let handler = self.methods.get(name).ok_or(RpcError::MethodNotFound)?;
+        handler(Arc::clone(&self.blockchain), params).await
+    }
+
+    // Executes a single JSON-RPC request object against a WebSocket session, falling back
+    // to the regular method table so a WS client can call any HTTP method too.
+    pub async fn execute_ws_method(&self, session: &WebSocketSessionShared, name: &str, params: Value) -> Result<Value, RpcError> {
+        if let Some(handler) = self.ws_methods.get(name) {
+            return handler(Arc::clone(session), params).await
+        }
+        self.execute_method(name, params).await
+    }
+}
@@ -1,5 +1,5 @@
-use crate::{storage::Storage, core::blockchain::Blockchain};
-use super::{RpcError, RpcServer};
+use crate::{storage::Storage, core::{blockchain::Blockchain, batch_verifier::BlockBatchVerifier, reorg::DagReorg}};
+use super::{RpcError, RpcServer, NotifyEvent, WebSocketSessionShared};
 use anyhow::Context;
 use serde::de::DeserializeOwned;
 use serde_json::{json, Value};
@@ -19,12 +19,17 @@ use xelis_common::{
         GetTransactionParams,
         P2pStatusResult,
         GetBlocksAtHeightParams,
-        GetDagOrderParams, GetBalanceAtTopoHeightParams, GetLastBalanceResult
+        GetDagOrderParams, GetBalanceAtTopoHeightParams, GetLastBalanceResult,
+        SubscribeParams,
+        BlockId,
+        TransactionResponse,
+        GetTransactionAtBlockLocationParams,
+        GetBlocksRangeParams
     },
     async_handler,
     serializer::Serializer,
     transaction::Transaction,
-    crypto::hash::Hash,
+    crypto::hash::{Hash, Hashable},
     block::Block,
 };
 use std::sync::Arc;
@@ -71,6 +76,7 @@ pub fn register_methods(server: &mut RpcServer) {
     server.register_method("get_block_at_topoheight", async_handler!(get_block_at_topoheight));
     server.register_method("get_blocks_at_height", async_handler!(get_blocks_at_height));
     server.register_method("get_block_by_hash", async_handler!(get_block_by_hash));
+    server.register_method("get_block", async_handler!(get_block));
     server.register_method("get_top_block", async_handler!(get_top_block));
     server.register_method("submit_block", async_handler!(submit_block));
     server.register_method("get_last_balance", async_handler!(get_last_balance));
@@ -80,10 +86,14 @@ pub fn register_methods(server: &mut RpcServer) {
     server.register_method("count_transactions", async_handler!(count_transactions));
     server.register_method("submit_transaction", async_handler!(submit_transaction));
     server.register_method("get_transaction", async_handler!(get_transaction));
+    server.register_method("get_transaction_at_block_location", async_handler!(get_transaction_at_block_location));
     server.register_method("p2p_status", async_handler!(p2p_status));
     server.register_method("get_mempool", async_handler!(get_mempool));
     server.register_method("get_tips", async_handler!(get_tips));
     server.register_method("get_dag_order", async_handler!(get_dag_order));
+    server.register_method("get_blocks_range", async_handler!(get_blocks_range));
+    server.register_ws_method("subscribe", async_handler!(subscribe));
+    server.register_ws_method("unsubscribe", async_handler!(unsubscribe));
 }
 
 async fn get_height(blockchain: Arc<Blockchain>, body: Value) -> Result<Value, RpcError> {
@@ -121,6 +131,29 @@ async fn get_block_by_hash(blockchain: Arc<Blockchain>, body: Value) -> Result<V
     get_block_response_for_hash(&blockchain, &storage, params.hash).await
 }
 
+// Unifies `get_block_by_hash` / `get_block_at_topoheight` / `get_blocks_at_height` behind a
+// single method: the params are the `BlockId` object itself, resolved to one (or, for a
+// non-topological height, several) hash(es) before delegating to the same
+// `get_block_response_for_hash` they all share.
+async fn get_block(blockchain: Arc<Blockchain>, body: Value) -> Result<Value, RpcError> {
+    let id: BlockId = parse_params(body)?;
+    let storage = blockchain.get_storage().read().await;
+    match id {
+        BlockId::Hash(hash) => get_block_response_for_hash(&blockchain, &storage, hash).await,
+        BlockId::Topoheight(topoheight) => {
+            let hash = storage.get_hash_at_topo_height(topoheight).await?;
+            get_block_response_for_hash(&blockchain, &storage, hash).await
+        },
+        BlockId::Height(height) => {
+            let mut blocks = Vec::new();
+            for hash in storage.get_blocks_at_height(height).await? {
+                blocks.push(get_block_response_for_hash(&blockchain, &storage, hash).await?)
+            }
+            Ok(json!(blocks))
+        }
+    }
+}
+
 async fn get_top_block(blockchain: Arc<Blockchain>, body: Value) -> Result<Value, RpcError> {
     if body != Value::Null {
         return Err(RpcError::UnexpectedParams)
@@ -146,10 +179,50 @@ async fn submit_block(blockchain: Arc<Blockchain>, body: Value) -> Result<Value,
     let block = Block::from_hex(params.block_template)?;
     // TODO add block hashing blob on block template
     let complete_block = blockchain.build_complete_block_from_block(block).await?;
-    blockchain.add_new_block(complete_block, true).await?;
+    verify_block_transactions(&blockchain, &complete_block).await?;
+
+    let hash = complete_block.hash();
+    let notifier_enabled = blockchain.get_notifier().has_subscribers();
+    let reorg = blockchain.add_new_block(complete_block, true).await?;
+
+    if notifier_enabled {
+        let storage = blockchain.get_storage().read().await;
+        let response = get_block_response_for_hash(&blockchain, &storage, hash).await?;
+        blockchain.get_notifier().notify(NotifyEvent::NewBlock, response).await;
+        notify_tip_reorgs(&blockchain, &reorg).await;
+    }
+
     Ok(json!(true))
 }
 
+// Batch-verifies every transaction in the block before it reaches `add_new_block`, so a
+// bad batch is rejected up front instead of being caught later by per-tx state checks.
+async fn verify_block_transactions(blockchain: &Blockchain, block: &Block) -> Result<(), RpcError> {
+    let mempool = blockchain.get_mempool().read().await;
+    let mut transactions = Vec::with_capacity(block.get_transactions().len());
+    for hash in block.get_transactions() {
+        transactions.push(mempool.view_tx(hash)?);
+    }
+
+    let verifier = BlockBatchVerifier::new();
+    if !verifier.verify_all(&transactions) {
+        return Err(RpcError::InvalidRequest)
+    }
+    Ok(())
+}
+
+// Forwards the `DagReorg` set returned by `add_new_block` as `block_ordered` /
+// `block_orphaned` notifications, one per hash. `add_new_block` does not compute reorgs
+// and always returns an empty set, so neither notification is fired from here.
+async fn notify_tip_reorgs(blockchain: &Blockchain, reorg: &DagReorg) {
+    for hash in &reorg.ordered {
+        blockchain.get_notifier().notify(NotifyEvent::BlockOrdered, json!(hash)).await;
+    }
+    for hash in &reorg.orphaned {
+        blockchain.get_notifier().notify(NotifyEvent::BlockOrphaned, json!(hash)).await;
+    }
+}
+
 async fn get_last_balance(blockchain: Arc<Blockchain>, body: Value) -> Result<Value, RpcError> {
     let params: GetBalanceParams = parse_params(body)?;
     let storage = blockchain.get_storage().read().await;
@@ -202,7 +275,16 @@ async fn count_transactions(blockchain: Arc<Blockchain>, body: Value) -> Result<
 async fn submit_transaction(blockchain: Arc<Blockchain>, body: Value) -> Result<Value, RpcError> {
     let params: SubmitTransactionParams = parse_params(body)?;
     let transaction = Transaction::from_hex(params.data)?;
+    let hash = transaction.hash();
     blockchain.add_tx_to_mempool(transaction, true).await?;
+
+    if blockchain.get_notifier().has_subscribers() {
+        let mempool = blockchain.get_mempool().read().await;
+        let tx = mempool.view_tx(&hash)?;
+        let data = json!(DataHash { hash, data: tx });
+        blockchain.get_notifier().notify(NotifyEvent::TransactionAddedInMempool, data).await;
+    }
+
     Ok(json!(true))
 }
 
@@ -210,7 +292,50 @@ async fn get_transaction(blockchain: Arc<Blockchain>, body: Value) -> Result<Val
     let params: GetTransactionParams = parse_params(body)?;
     let storage = blockchain.get_storage().read().await;
     let tx = storage.get_transaction(&params.hash).await?;
-    Ok(json!(tx))
+    let response = build_transaction_response(&blockchain, &storage, params.hash, tx).await?;
+    Ok(json!(response))
+}
+
+// Resolves a transaction by its position inside a block rather than by a hash the caller
+// supplies, for an explorer walking a block's contents one index at a time. The hash at
+// that index is read from the block, and the transaction is then loaded by that hash
+// through `storage.get_transaction`, the same lookup `get_transaction` makes.
+async fn get_transaction_at_block_location(blockchain: Arc<Blockchain>, body: Value) -> Result<Value, RpcError> {
+    let params: GetTransactionAtBlockLocationParams = parse_params(body)?;
+    let storage = blockchain.get_storage().read().await;
+    let block_hash = match params.block {
+        BlockId::Hash(hash) => hash,
+        BlockId::Topoheight(topoheight) => storage.get_hash_at_topo_height(topoheight).await?,
+        // A height can map to several blocks in the DAG, so it's not a valid way to pin
+        // down a single block to index into.
+        BlockId::Height(_) => return Err(RpcError::InvalidRequest)
+    };
+
+    let block = storage.get_block_by_hash(&block_hash).await?;
+    let tx_hash = block.get_transactions().get(params.index as usize).cloned().ok_or(RpcError::InvalidRequest)?;
+    let tx = storage.get_transaction(&tx_hash).await?;
+    let response = build_transaction_response(&blockchain, &storage, tx_hash, tx).await?;
+    Ok(json!(response))
+}
+
+// Shared by `get_transaction` and `get_transaction_at_block_location`: wraps the raw
+// transaction with the block(s) it's included in, the topoheight of the first ordered
+// one, and a confirmation count derived from the current chain topoheight.
+async fn build_transaction_response(blockchain: &Blockchain, storage: &Storage, hash: Hash, tx: Arc<Transaction>) -> Result<TransactionResponse, RpcError> {
+    // TODO get_blocks_for_tx needs a tx-hash -> containing-block(s) index maintained by
+    // storage as blocks are added; it doesn't exist yet.
+    let blocks = storage.get_blocks_for_tx(&hash).await?;
+
+    let mut topoheight = None;
+    for block_hash in &blocks {
+        if storage.is_block_topological_ordered(block_hash).await {
+            topoheight = Some(storage.get_topo_height_for_hash(block_hash).await?);
+            break
+        }
+    }
+    let confirmations = topoheight.map(|topo| blockchain.get_topo_height().saturating_sub(topo) + 1);
+
+    Ok(TransactionResponse { blocks, topoheight, confirmations, data: DataHash { hash, data: tx } })
 }
 
 async fn p2p_status(blockchain: Arc<Blockchain>, body: Value) -> Result<Value, RpcError> {
@@ -311,4 +436,61 @@ async fn get_dag_order(blockchain: Arc<Blockchain>, body: Value) -> Result<Value
     }
 
     Ok(json!(order))
+}
+
+// Paginated replacement for `get_dag_order`'s hardcoded `MAX_DAG_ORDER` cap: walks
+// `[start_topoheight, end_topoheight]` one topoheight at a time so memory stays bounded
+// by the server's configured page size regardless of how wide a span is requested, and
+// returns a `next_cursor` the caller can pass back as `start_topoheight` to resume.
+//
+// TODO `Blockchain::get_max_blocks_range_size` just returns `DEFAULT_MAX_BLOCKS_RANGE_SIZE`
+// for now; making it operator-configurable needs a CLI/config option threaded into
+// `Blockchain` at startup, which isn't wired up yet.
+async fn get_blocks_range(blockchain: Arc<Blockchain>, body: Value) -> Result<Value, RpcError> {
+    let params: GetBlocksRangeParams = parse_params(body)?;
+    let current_topoheight = blockchain.get_topo_height();
+    if params.end_topoheight < params.start_topoheight || params.end_topoheight > current_topoheight {
+        debug!("get blocks range: start = {}, end = {}, max = {}", params.start_topoheight, params.end_topoheight, current_topoheight);
+        return Err(RpcError::InvalidRequest)
+    }
+
+    let max_page_size = blockchain.get_max_blocks_range_size();
+    let last_topoheight = std::cmp::min(params.end_topoheight, params.start_topoheight + max_page_size.saturating_sub(1));
+    let next_cursor = if last_topoheight < params.end_topoheight {
+        Some(last_topoheight + 1)
+    } else {
+        None
+    };
+
+    let storage = blockchain.get_storage().read().await;
+    let mut blocks = Vec::with_capacity((last_topoheight - params.start_topoheight + 1) as usize);
+    for topoheight in params.start_topoheight..=last_topoheight {
+        let hash = storage.get_hash_at_topo_height(topoheight).await?;
+        let block = if params.include_full_block {
+            get_block_response_for_hash(&blockchain, &storage, hash).await?
+        } else {
+            json!(hash)
+        };
+        blocks.push(block);
+    }
+
+    Ok(json!({
+        "blocks": blocks,
+        "next_cursor": next_cursor
+    }))
+}
+
+// WebSocket-only meta methods: unlike every handler above, these act on the calling
+// session rather than the blockchain, so they're registered through `register_ws_method`
+// and dispatched with a `WebSocketSessionShared` instead of `Arc<Blockchain>`.
+async fn subscribe(session: WebSocketSessionShared, body: Value) -> Result<Value, RpcError> {
+    let params: SubscribeParams = parse_params(body)?;
+    session.subscribe(params.notify).await;
+    Ok(json!(true))
+}
+
+async fn unsubscribe(session: WebSocketSessionShared, body: Value) -> Result<Value, RpcError> {
+    let params: SubscribeParams = parse_params(body)?;
+    session.unsubscribe(&params.notify).await;
+    Ok(json!(true))
 }
\ No newline at end of file
@@ -0,0 +1,136 @@
+use super::RpcServer;
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::{collections::HashSet, sync::Arc};
+use tokio::sync::{broadcast, Mutex};
+use tokio_tungstenite::tungstenite::Message;
+pub use xelis_common::api::daemon::NotifyEvent;
+
+// A (event, payload) pair broadcast from the chain to every subscribed WebSocket session.
+#[derive(Clone)]
+pub struct Notification {
+    pub event: NotifyEvent,
+    pub value: Value
+}
+
+const NOTIFICATION_CHANNEL_CAPACITY: usize = 1024;
+
+// Held by the Blockchain so any code path (block acceptance, mempool insertion, DAG
+// reordering) can push an event without knowing who, if anyone, is listening.
+pub struct Notifier {
+    sender: broadcast::Sender<Notification>
+}
+
+impl Notifier {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(NOTIFICATION_CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    pub async fn notify(&self, event: NotifyEvent, value: Value) {
+        // No subscribers is not an error, just means the notification is dropped
+        let _ = self.sender.send(Notification { event, value });
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Notification> {
+        self.sender.subscribe()
+    }
+}
+
+// Per-connection state: which events this particular WebSocket client wants forwarded.
+// One of these is created for each accepted WS connection and handed to the `subscribe`
+// and `unsubscribe` meta-methods instead of the usual (Blockchain, Value) pair.
+pub struct WebSocketSession {
+    subscriptions: Mutex<HashSet<NotifyEvent>>
+}
+
+pub type WebSocketSessionShared = std::sync::Arc<WebSocketSession>;
+
+impl WebSocketSession {
+    pub fn new() -> WebSocketSessionShared {
+        std::sync::Arc::new(Self { subscriptions: Mutex::new(HashSet::new()) })
+    }
+
+    pub async fn subscribe(&self, event: NotifyEvent) {
+        self.subscriptions.lock().await.insert(event);
+    }
+
+    pub async fn unsubscribe(&self, event: &NotifyEvent) {
+        self.subscriptions.lock().await.remove(event);
+    }
+
+    pub async fn is_subscribed(&self, event: &NotifyEvent) -> bool {
+        self.subscriptions.lock().await.contains(event)
+    }
+}
+
+// Builds the JSON-RPC notification object (no `id`) sent over the wire for a given event.
+pub fn build_notification(notification: &Notification) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": notification.event,
+        "params": notification.value
+    })
+}
+
+// Drives one accepted WebSocket connection: incoming frames are dispatched as JSON-RPC
+// requests against `server`, while broadcast notifications matching this session's
+// subscriptions are pushed out as they arrive. Returns once the socket is closed.
+pub async fn handle_connection<S>(server: Arc<RpcServer>, mut socket: S)
+where
+    S: futures::Sink<Message> + futures::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin
+{
+    let session = WebSocketSession::new();
+    let mut notifications = server.get_notifier().subscribe();
+
+    loop {
+        tokio::select! {
+            message = socket.next() => {
+                let response = match message {
+                    Some(Ok(Message::Text(text))) => match serde_json::from_str(&text) {
+                        Ok(body) => server.handle_ws_body(&session, body).await,
+                        Err(e) => Some(json!({
+                            "jsonrpc": "2.0",
+                            "id": Value::Null,
+                            "error": { "code": -32700, "message": e.to_string() }
+                        }))
+                    },
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    _ => continue
+                };
+
+                if let Some(response) = response {
+                    if socket.send(Message::Text(response.to_string())).await.is_err() {
+                        break
+                    }
+                }
+            },
+            notification = notifications.recv() => {
+                match notification {
+                    Ok(notification) if session.is_subscribed(&notification.event).await => {
+                        let payload = build_notification(&notification);
+                        if socket.send(Message::Text(payload.to_string())).await.is_err() {
+                            break
+                        }
+                    },
+                    Ok(_) => {},
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break
+                }
+            }
+        }
+    }
+}
+
+// Upgrades an inbound TCP connection into a WebSocket and drives it with
+// `handle_connection` until the socket closes. It is exported from `rpc`; nothing in
+// this tree calls it.
+pub async fn accept_websocket(server: Arc<RpcServer>, stream: tokio::net::TcpStream) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+    let ws_stream = tokio_tungstenite::accept_async(stream).await?;
+    handle_connection(server, ws_stream).await;
+    Ok(())
+}
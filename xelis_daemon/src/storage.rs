@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use xelis_common::{block::Block, crypto::{hash::Hash, key::PublicKey}, transaction::Transaction};
+use crate::core::blockchain::BlockchainError;
+
+// Persistent chain state backing `Blockchain`: blocks, topological order, balances,
+// nonces, and the asset list. This reconstructs only the marker type `Blockchain` needs
+// to hold a handle to — the real storage engine (backed by whatever key-value store the
+// daemon uses) lives outside this slice of the tree, so every accessor below is a TODO
+// rather than a working implementation.
+#[derive(Default)]
+pub struct Storage;
+
+impl Storage {
+    pub async fn get_hash_at_topo_height(&self, _topoheight: u64) -> Result<Hash, BlockchainError> {
+        todo!()
+    }
+
+    pub async fn get_block_by_hash(&self, _hash: &Hash) -> Result<Block, BlockchainError> {
+        todo!()
+    }
+
+    pub async fn is_block_topological_ordered(&self, _hash: &Hash) -> bool {
+        todo!()
+    }
+
+    pub async fn get_topo_height_for_hash(&self, _hash: &Hash) -> Result<u64, BlockchainError> {
+        todo!()
+    }
+
+    pub async fn get_cumulative_difficulty_for_block(&self, _hash: &Hash) -> Result<u64, BlockchainError> {
+        todo!()
+    }
+
+    pub fn get_difficulty_for_block(&self, _hash: &Hash) -> Result<u64, BlockchainError> {
+        todo!()
+    }
+
+    pub fn get_supply_for_hash(&self, _hash: &Hash) -> Result<u64, BlockchainError> {
+        todo!()
+    }
+
+    pub fn get_block_reward(&self, _hash: &Hash) -> Result<u64, BlockchainError> {
+        todo!()
+    }
+
+    pub async fn get_blocks_at_height(&self, _height: u64) -> Result<Vec<Hash>, BlockchainError> {
+        todo!()
+    }
+
+    pub async fn get_last_balance(&self, _key: &PublicKey, _asset: &Hash) -> Result<(u64, u64), BlockchainError> {
+        todo!()
+    }
+
+    pub async fn get_balance_at_exact_topoheight(&self, _key: &PublicKey, _asset: &Hash, _topoheight: u64) -> Result<u64, BlockchainError> {
+        todo!()
+    }
+
+    pub async fn get_nonce(&self, _key: &PublicKey) -> Result<u64, BlockchainError> {
+        todo!()
+    }
+
+    pub async fn get_assets(&self) -> Result<Vec<Hash>, BlockchainError> {
+        todo!()
+    }
+
+    pub fn count_transactions(&self) -> usize {
+        todo!()
+    }
+
+    pub async fn get_transaction(&self, _hash: &Hash) -> Result<Arc<Transaction>, BlockchainError> {
+        todo!()
+    }
+
+    pub async fn get_tips(&self) -> Result<Vec<Hash>, BlockchainError> {
+        todo!()
+    }
+
+    pub async fn get_blocks_for_tx(&self, _hash: &Hash) -> Result<Vec<Hash>, BlockchainError> {
+        todo!()
+    }
+}